@@ -3,7 +3,7 @@ use std::error::Error;
 use std::ffi::OsString;
 use std::fs::{self, File};
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use cargo::core::compiler::{CompileKind, CompileMode, CompileTarget, UnitOutput};
@@ -13,10 +13,39 @@ use cargo::ops::{CompileOptions, Packages};
 use cargo::GlobalContext;
 use clap::Parser;
 use gix::interrupt::IS_INTERRUPTED;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use time::OffsetDateTime;
 use toml::Table;
 use walkdir::WalkDir;
 use zip::write::SimpleFileOptions;
-use zip::ZipWriter;
+use zip::{DateTime, ZipWriter};
+
+// Default modification timestamp for reproducible archives (1980-01-01 00:00:00 UTC),
+// the earliest timestamp the ZIP format can represent
+const DEFAULT_SOURCE_DATE_EPOCH: i64 = 315532800;
+
+// Default Git repository used when neither `--repository` nor a profile specify one
+const DEFAULT_REPOSITORY: &str = "https://github.com/ferronweb/ferron.git";
+
+// Name of the config file discovered in the working directory when `--config` isn't passed
+const DEFAULT_CONFIG_FILE_NAME: &str = "forge.toml";
+
+// Top-level `forge.toml` schema: a table of named, reusable build presets
+#[derive(Deserialize, Debug, Default)]
+struct ForgeConfig {
+  #[serde(default)]
+  profile: std::collections::HashMap<String, ForgeProfile>,
+}
+
+// A named preset mapping to a set of modules, a default target, repository and toolchain
+#[derive(Deserialize, Debug, Default, Clone)]
+struct ForgeProfile {
+  modules: Option<Vec<String>>,
+  target: Option<String>,
+  repository: Option<String>,
+  toolchain: Option<String>,
+}
 
 // Struct for command-line arguments using `clap`
 /// A compilation tool for easy compiling of Ferron web server
@@ -32,119 +61,671 @@ struct Args {
   #[arg(short, long)]
   modules: Option<Vec<String>>,
 
-  /// Target triple for cross-compilation
-  #[arg(short, long)]
-  target: Option<String>,
+  /// Target triple(s) for cross-compilation. Pass a comma-separated list
+  /// (e.g. "x86_64-unknown-linux-gnu,aarch64-apple-darwin") to build a
+  /// full release matrix in one invocation, producing one archive per target
+  #[arg(short, long, value_delimiter = ',')]
+  target: Option<Vec<String>>,
 
   /// Git repository URL containing Ferron's source code
-  #[arg(short, long, default_value_t = String::from("https://github.com/ferronweb/ferron.git"))]
-  repository: String,
+  #[arg(short, long)]
+  repository: Option<String>,
 
-  /// Path to the output ZIP archive
+  /// Path to the output archive. When compiling for multiple targets,
+  /// the target triple is inserted before the file extension of each archive
   #[arg(short, long, default_value_t = String::from("ferron-custom.zip"))]
   output: String,
+
+  /// Additional rustup components to install for the resolved toolchain
+  /// before compiling (e.g. "rust-src,llvm-tools"), unless `--no-auto-install`
+  /// is passed
+  #[arg(long, value_delimiter = ',')]
+  component: Option<Vec<String>>,
+
+  /// Don't automatically `rustup target add` a requested target triple
+  /// when its standard library isn't installed for the resolved toolchain
+  #[arg(long)]
+  no_auto_install: bool,
+
+  /// Rust toolchain channel, version, or path to build with (e.g. "stable",
+  /// "nightly-2024-06-07", "1.75.0"), overriding the rustup default
+  #[arg(long)]
+  toolchain: Option<String>,
+
+  /// Honor a `rust-toolchain.toml` (or legacy `rust-toolchain`) file present
+  /// in the cloned Ferron repo, taking precedence over the rustup default
+  #[arg(long)]
+  respect_repo_toolchain: bool,
+
+  /// Path to a `forge.toml` config file. Defaults to `forge.toml` in the
+  /// current directory, if present
+  #[arg(long)]
+  config: Option<String>,
+
+  /// Named build preset to load from the config file, providing default
+  /// modules, target, repository and toolchain. CLI flags take precedence
+  #[arg(short, long)]
+  profile: Option<String>,
+
+  /// Persistent checkout directory to reuse across runs, preserving the
+  /// `target/` directory for incremental rebuilds. Defaults to a per-repository
+  /// directory under the user's cache directory
+  #[arg(long)]
+  work_dir: Option<String>,
+
+  /// Always clone into a fresh temporary directory instead of reusing (or
+  /// creating) a persistent cached checkout
+  #[arg(long)]
+  no_cache: bool,
+
+  /// Output archive format. Inferred from the `--output` extension when omitted
+  #[arg(long, value_enum)]
+  format: Option<ArchiveFormat>,
 }
 
 fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
   // Parse command-line arguments
   let args = Args::parse();
 
-  println!("Creating temporary directory...");
-  let temporary_directory = tempfile::tempdir()?; // Create a temporary directory
+  // Load the named profile from `forge.toml`, if one was requested, and
+  // merge it with the CLI flags (CLI flags always win)
+  let profile = load_profile(args.config.as_deref(), args.profile.as_deref())?;
+  let repository = args
+    .repository
+    .clone()
+    .or_else(|| profile.as_ref().and_then(|profile| profile.repository.clone()))
+    .unwrap_or_else(|| DEFAULT_REPOSITORY.to_string());
+  let toolchain = args
+    .toolchain
+    .clone()
+    .or_else(|| profile.as_ref().and_then(|profile| profile.toolchain.clone()));
+  let modules = args
+    .modules
+    .clone()
+    .or_else(|| profile.as_ref().and_then(|profile| profile.modules.clone()));
+  let target = args
+    .target
+    .clone()
+    .or_else(|| profile.as_ref().and_then(|profile| profile.target.clone()).map(|target| vec![target]));
+
+  // Prepare the repository checkout, reusing a persistent cached checkout
+  // across runs (so Cargo's `target/` directory survives) unless `--no-cache`
+  // was passed. `_temporary_directory_guard` keeps a temporary clone
+  // directory alive for the rest of `main`
+  let (workspace_directory, _temporary_directory_guard) = prepare_workspace(
+    &repository,
+    &args.ferron_version,
+    args.work_dir.as_deref(),
+    args.no_cache,
+  )?;
 
-  println!("Cloning the Git repository...");
-  // Clone the specified Git repository and checkout the desired ref
-  let prepare_clone = gix::prepare_clone(args.repository, &temporary_directory)?;
-  let (mut prepare_checkout, _) = prepare_clone
-    .with_ref_name(args.ferron_version.as_str().into())?
-    .fetch_then_checkout(gix::progress::Discard, &IS_INTERRUPTED)?;
-  let (repo, _) = prepare_checkout.main_worktree(gix::progress::Discard, &IS_INTERRUPTED)?;
+  // Build once per requested target, falling back to a single host build
+  // when neither `--target` nor the profile gave one
+  let targets: Vec<Option<String>> = match target {
+    Some(triplets) => triplets.into_iter().map(Some).collect(),
+    None => vec![None],
+  };
+  let matrix_build = targets.len() > 1;
+
+  for target in &targets {
+    println!(
+      "Compiling Ferron for \"{}\" target...",
+      target.as_deref().unwrap_or("host")
+    );
+    // Compile the project and retrieve the compiled binaries
+    let (binaries, target_triple) = compile(
+      workspace_directory.to_path_buf(),
+      target.as_deref(),
+      modules.as_deref(),
+      !args.no_auto_install,
+      args.component.as_deref(),
+      toolchain.as_deref(),
+      args.respect_repo_toolchain,
+    )?;
+
+    let output_path = output_path_for_target(&args.output, &target_triple, matrix_build);
+
+    println!("Creating archive...");
+    write_archive(
+      &binaries,
+      &target_triple,
+      &workspace_directory,
+      &output_path,
+      args.format,
+    )?;
+
+    println!(
+      "Built Ferron for \"{}\" target successfully!",
+      target_triple
+    );
+  }
+
+  Ok(())
+}
+
+// Output packaging format, inferred from the `--output` extension when not given explicitly
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum ArchiveFormat {
+  Zip,
+  #[value(name = "tar.gz")]
+  TarGz,
+}
+
+// Permission bits and packaging-agnostic metadata for a single archive entry
+#[derive(Clone, Copy)]
+struct EntryOptions {
+  mode: u32,
+}
+
+// Common archive-writing operations implemented for each supported packaging
+// format, so `write_archive` can assemble binaries, `wwwroot` assets, and the
+// checksum manifest the same way regardless of the chosen `--format`
+trait ArchiveWriter {
+  fn start_file(&mut self, name: &str, options: EntryOptions) -> Result<(), Box<dyn Error + Send + Sync>>;
+  fn write_all(&mut self, data: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>>;
+  fn add_directory(&mut self, name: &str, options: EntryOptions) -> Result<(), Box<dyn Error + Send + Sync>>;
+  fn set_comment(&mut self, comment: String);
+  fn finish(self: Box<Self>) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+// ZIP packaging backend. A fixed modification timestamp (derived from
+// `SOURCE_DATE_EPOCH` when set) keeps the archive byte-for-byte reproducible
+// across runs of the same source and module selection
+struct ZipArchiveWriter {
+  zip: ZipWriter<File>,
+  timestamp: DateTime,
+}
+
+impl ZipArchiveWriter {
+  fn create(output_path: &Path) -> Result<Self, Box<dyn Error + Send + Sync>> {
+    Ok(Self {
+      zip: ZipWriter::new(File::create(output_path)?),
+      timestamp: reproducible_zip_timestamp()?,
+    })
+  }
+
+  fn zip_options(&self, options: EntryOptions) -> SimpleFileOptions {
+    SimpleFileOptions::default()
+      .unix_permissions(options.mode)
+      .last_modified_time(self.timestamp)
+  }
+}
+
+impl ArchiveWriter for ZipArchiveWriter {
+  fn start_file(&mut self, name: &str, options: EntryOptions) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let zip_options = self.zip_options(options);
+    self.zip.start_file(name, zip_options)?;
+    Ok(())
+  }
+
+  fn write_all(&mut self, data: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>> {
+    self.zip.write_all(data)?;
+    Ok(())
+  }
+
+  fn add_directory(&mut self, name: &str, options: EntryOptions) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let zip_options = self.zip_options(options);
+    self.zip.add_directory(name, zip_options)?;
+    Ok(())
+  }
+
+  fn set_comment(&mut self, comment: String) {
+    self.zip.set_comment(comment);
+  }
+
+  fn finish(self: Box<Self>) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let mut zip = self.zip;
+    zip.finish()?;
+    Ok(())
+  }
+}
+
+// tar.gz packaging backend for Unix-native distribution. Entries are
+// buffered until the next `start_file`/`add_directory`/`finish` call, since
+// tar headers require the entry size to be known up front
+struct TarGzArchiveWriter {
+  builder: tar::Builder<flate2::write::GzEncoder<File>>,
+  mtime: u64,
+  pending: Option<(String, EntryOptions, Vec<u8>)>,
+}
+
+impl TarGzArchiveWriter {
+  fn create(output_path: &Path) -> Result<Self, Box<dyn Error + Send + Sync>> {
+    let encoder = flate2::write::GzEncoder::new(File::create(output_path)?, flate2::Compression::default());
+    Ok(Self {
+      builder: tar::Builder::new(encoder),
+      mtime: resolve_source_date_epoch()?.max(0) as u64,
+      pending: None,
+    })
+  }
+
+  fn flush_pending(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let Some((name, options, data)) = self.pending.take() else {
+      return Ok(());
+    };
+
+    let mut header = tar::Header::new_gnu();
+    header.set_path(&name)?;
+    header.set_size(data.len() as u64);
+    header.set_mode(options.mode);
+    header.set_mtime(self.mtime);
+    header.set_cksum();
+    self.builder.append(&header, data.as_slice())?;
+
+    Ok(())
+  }
+}
+
+impl ArchiveWriter for TarGzArchiveWriter {
+  fn start_file(&mut self, name: &str, options: EntryOptions) -> Result<(), Box<dyn Error + Send + Sync>> {
+    self.flush_pending()?;
+    self.pending = Some((name.to_string(), options, Vec::new()));
+    Ok(())
+  }
+
+  fn write_all(&mut self, data: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>> {
+    match &mut self.pending {
+      Some((_, _, buffer)) => {
+        buffer.extend_from_slice(data);
+        Ok(())
+      }
+      None => Err(anyhow::anyhow!("`write_all` called before `start_file`"))?,
+    }
+  }
+
+  fn add_directory(&mut self, name: &str, options: EntryOptions) -> Result<(), Box<dyn Error + Send + Sync>> {
+    self.flush_pending()?;
+
+    let mut header = tar::Header::new_gnu();
+    header.set_path(&format!("{}/", name.trim_end_matches('/')))?;
+    header.set_size(0);
+    header.set_mode(options.mode);
+    header.set_mtime(self.mtime);
+    header.set_entry_type(tar::EntryType::Directory);
+    header.set_cksum();
+    self.builder.append(&header, io::empty())?;
+
+    Ok(())
+  }
+
+  fn set_comment(&mut self, _comment: String) {
+    // tar.gz archives have no native comment field, unlike ZIP
+  }
 
-  // Determine the working directory of the repository
-  let workspace_directory = match repo.workdir() {
-    Some(workdir) => workdir,
-    None => Err(anyhow::anyhow!("Workspace directory not found"))?,
+  fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error + Send + Sync>> {
+    self.flush_pending()?;
+    self.builder.finish()?;
+    Ok(())
+  }
+}
+
+// Resolves which archive format to use: an explicit `--format` always wins,
+// otherwise the format is inferred from the `--output` file extension
+fn resolve_archive_format(explicit: Option<ArchiveFormat>, output_path: &Path) -> ArchiveFormat {
+  if let Some(format) = explicit {
+    return format;
+  }
+
+  let file_name = output_path
+    .file_name()
+    .map(|name| name.to_string_lossy().to_lowercase())
+    .unwrap_or_default();
+
+  if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+    ArchiveFormat::TarGz
+  } else {
+    ArchiveFormat::Zip
+  }
+}
+
+// Derives the per-target archive path from the user-supplied `--output`,
+// inserting the target triple before the file extension for matrix builds
+fn output_path_for_target(output: &str, target_triple: &str, matrix_build: bool) -> PathBuf {
+  if !matrix_build {
+    return PathBuf::from(output);
+  }
+
+  let output_path = Path::new(output);
+  let file_name = output_path
+    .file_name()
+    .map(|name| name.to_string_lossy().to_string())
+    .unwrap_or_else(|| output.to_string());
+
+  // Treat `.tar.gz` as a single compound extension rather than splitting on the last dot
+  let (stem, suffix) = if file_name.to_lowercase().ends_with(".tar.gz") {
+    (file_name[..file_name.len() - ".tar.gz".len()].to_string(), Some(".tar.gz".to_string()))
+  } else {
+    match Path::new(&file_name).extension() {
+      Some(extension) => {
+        let stem = Path::new(&file_name).file_stem().unwrap().to_string_lossy().to_string();
+        (stem, Some(format!(".{}", extension.to_string_lossy())))
+      }
+      None => (file_name, None),
+    }
   };
 
-  println!("Compiling Ferron...");
-  // Compile the project and retrieve the compiled binaries
-  let (binaries, target_triple) = compile(
-    workspace_directory.to_path_buf(),
-    args.target.as_ref().map(|s| s as &str),
-    args.modules.as_deref(),
-  )?;
+  let new_file_name = match suffix {
+    Some(suffix) => format!("{}-{}{}", stem, target_triple, suffix),
+    None => format!("{}-{}", stem, target_triple),
+  };
+
+  match output_path.parent() {
+    Some(parent) if !parent.as_os_str().is_empty() => parent.join(new_file_name),
+    _ => PathBuf::from(new_file_name),
+  }
+}
+
+// Packages the compiled binaries and `wwwroot` static assets into an archive
+fn write_archive(
+  binaries: &[UnitOutput],
+  target_triple: &str,
+  workspace_directory: &Path,
+  output_path: &Path,
+  format: Option<ArchiveFormat>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+  let mut writer: Box<dyn ArchiveWriter> = match resolve_archive_format(format, output_path) {
+    ArchiveFormat::Zip => Box::new(ZipArchiveWriter::create(output_path)?),
+    ArchiveFormat::TarGz => Box::new(TarGzArchiveWriter::create(output_path)?),
+  };
 
-  println!("Creating ZIP archive...");
-  // Set up a ZIP writer
-  let zip_options = SimpleFileOptions::default();
-  let zip_binary_options = SimpleFileOptions::default().unix_permissions(0o755);
-  let zip_file = File::create(args.output)?;
-  let mut zip = ZipWriter::new(zip_file);
+  // Records of (path, SHA-256 digest, byte length) for every packaged entry,
+  // collected as files are streamed into the archive and written out as a
+  // `MANIFEST.sha256` entry so downstream consumers can verify the build
+  let mut manifest_records: Vec<(String, String, u64)> = Vec::new();
 
-  // Add each compiled binary to the ZIP
+  // Add each compiled binary to the archive
   for binary in binaries {
-    let binary_path = binary.path;
+    let binary_path = &binary.path;
     let binary_filename = match binary_path.file_name() {
       Some(filename) => filename.to_string_lossy().to_string(),
       None => continue,
     };
     let mut binary_file = File::open(binary_path)?;
-    zip.start_file(binary_filename, zip_binary_options)?;
-    io::copy(&mut binary_file, &mut zip)?;
+    writer.start_file(&binary_filename, EntryOptions { mode: 0o755 })?;
+    let (digest, size) = copy_with_hash(&mut binary_file, writer.as_mut())?;
+    manifest_records.push((binary_filename, digest, size));
   }
 
-  // Add default configuration file to ZIP
-  zip.start_file("ferron.yaml", zip_options)?;
-  zip.write_all(
-    r#"global:
-  wwwroot: wwwroot"#
-      .as_bytes(),
-  )?;
+  // Add default configuration file to the archive
+  let ferron_yaml = r#"global:
+  wwwroot: wwwroot"#;
+  writer.start_file("ferron.yaml", EntryOptions { mode: 0o644 })?;
+  let (digest, size) = copy_with_hash(io::Cursor::new(ferron_yaml.as_bytes()), writer.as_mut())?;
+  manifest_records.push(("ferron.yaml".to_string(), digest, size));
 
-  // Add `wwwroot` static assets to the ZIP
+  // Add `wwwroot` static assets to the archive, sorted by path rather than
+  // filesystem order so the resulting archive is byte-for-byte reproducible
   let mut webroot_path = workspace_directory.to_path_buf();
   webroot_path.push("wwwroot");
-  let walkdir_webroot = WalkDir::new(&webroot_path).into_iter();
+  let mut walkdir_webroot = WalkDir::new(&webroot_path)
+    .into_iter()
+    .collect::<Result<Vec<_>, _>>()?;
+  walkdir_webroot.sort_by(|a, b| a.path().cmp(b.path()));
 
-  for entry_result in walkdir_webroot {
-    let entry = entry_result?;
+  for entry in walkdir_webroot {
     let path = entry.path();
     let name = path.strip_prefix(&webroot_path).unwrap();
     let path_as_string = name.to_str().map(str::to_owned);
 
     if let Some(path_as_string) = path_as_string {
       if path.is_file() {
-        // Add individual file to the ZIP
-        zip.start_file(path_as_string, zip_options)?;
+        // Add individual file to the archive
+        writer.start_file(&path_as_string, EntryOptions { mode: 0o644 })?;
         let mut file = File::open(path)?;
-        io::copy(&mut file, &mut zip)?;
+        let (digest, size) = copy_with_hash(&mut file, writer.as_mut())?;
+        manifest_records.push((path_as_string, digest, size));
       } else if !name.as_os_str().is_empty() {
-        // Add directory entry to the ZIP
-        zip.add_directory(path_as_string, zip_options)?;
+        // Add directory entry to the archive
+        writer.add_directory(&path_as_string, EntryOptions { mode: 0o755 })?;
       }
     }
   }
 
-  // Add a comment to the ZIP metadata
-  zip.set_comment(
-    format!(
-      "Ferron built for \"{}\" target using Ferron Forge",
-      target_triple
-    )
-    .as_str(),
-  );
+  // Write the collected checksums as the final archive entry
+  let manifest = manifest_records
+    .into_iter()
+    .map(|(name, digest, size)| format!("{}  {}  {}\n", digest, size, name))
+    .collect::<String>();
+  writer.start_file("MANIFEST.sha256", EntryOptions { mode: 0o644 })?;
+  writer.write_all(manifest.as_bytes())?;
+
+  // Record the build's provenance where the format supports archive-level metadata
+  writer.set_comment(format!(
+    "Ferron built for \"{}\" target using Ferron Forge",
+    target_triple
+  ));
+
+  // Finalize the archive
+  writer.finish()?;
+
+  Ok(())
+}
+
+// Streams `reader` into `writer` while hashing the bytes as they pass through,
+// returning the hex-encoded SHA-256 digest and byte length of the copied data
+fn copy_with_hash<R: io::Read>(
+  mut reader: R,
+  writer: &mut (impl ArchiveWriter + ?Sized),
+) -> Result<(String, u64), Box<dyn Error + Send + Sync>> {
+  let mut hasher = Sha256::new();
+  let mut buffer = [0u8; 8192];
+  let mut size = 0u64;
+
+  loop {
+    let read = reader.read(&mut buffer)?;
+    if read == 0 {
+      break;
+    }
+    hasher.update(&buffer[..read]);
+    writer.write_all(&buffer[..read])?;
+    size += read as u64;
+  }
+
+  Ok((format!("{:x}", hasher.finalize()), size))
+}
+
+// Resolves the fixed modification timestamp used for every ZIP entry, honoring
+// `SOURCE_DATE_EPOCH` (seconds since the Unix epoch) when present so archives
+// stay reproducible for supply-chain verification and caching
+fn reproducible_zip_timestamp() -> Result<DateTime, Box<dyn Error + Send + Sync>> {
+  let epoch = resolve_source_date_epoch()?;
+
+  // The ZIP format can only represent dates between 1980-01-01 and
+  // 2107-12-31. Rather than hard-failing on an out-of-range
+  // `SOURCE_DATE_EPOCH` (e.g. `0`, a perfectly valid Unix epoch), clamp to
+  // the representable floor, matching how reproducible-build tooling treats it
+  let zip_date_time = OffsetDateTime::from_unix_timestamp(epoch)
+    .ok()
+    .and_then(|offset_date_time| DateTime::try_from(offset_date_time).ok());
+
+  match zip_date_time {
+    Some(zip_date_time) => Ok(zip_date_time),
+    None => Ok(DateTime::try_from(OffsetDateTime::from_unix_timestamp(
+      DEFAULT_SOURCE_DATE_EPOCH,
+    )?)?),
+  }
+}
+
+// Resolves `SOURCE_DATE_EPOCH` (seconds since the Unix epoch) when present,
+// falling back to a constant default, so every packaging format can derive
+// the same fixed modification timestamp for reproducible archives
+fn resolve_source_date_epoch() -> Result<i64, Box<dyn Error + Send + Sync>> {
+  match std::env::var("SOURCE_DATE_EPOCH") {
+    Ok(value) => Ok(value.parse::<i64>()?),
+    Err(_) => Ok(DEFAULT_SOURCE_DATE_EPOCH),
+  }
+}
+
+// Prepares a working checkout of the Ferron repository, reusing a persistent
+// cached checkout across runs when possible so that Cargo's `target/`
+// directory (and thus incremental compilation) survives between invocations.
+// Returns the checkout path along with an optional `TempDir` guard that must
+// be kept alive for the checkout to remain on disk (only set when a temporary
+// clone is used: for `--no-cache`, or after updating a cached checkout fails)
+fn prepare_workspace(
+  repository: &str,
+  ferron_version: &str,
+  work_dir: Option<&str>,
+  no_cache: bool,
+) -> Result<(PathBuf, Option<tempfile::TempDir>), Box<dyn Error + Send + Sync>> {
+  if no_cache {
+    return fallback_temp_clone(repository, ferron_version);
+  }
 
-  // Finalize the ZIP archive
-  zip.finish()?;
+  let cached_directory = match work_dir {
+    Some(work_dir) => PathBuf::from(work_dir),
+    None => cache_dir_for_repository(repository),
+  };
+
+  if cached_directory.join(".git").is_dir() {
+    println!(
+      "Reusing cached checkout at \"{}\"...",
+      cached_directory.display()
+    );
+    match fetch_and_checkout(&cached_directory, ferron_version) {
+      Ok(()) => return Ok((cached_directory, None)),
+      Err(error) => {
+        println!(
+          "Failed to update the cached checkout at \"{}\" ({}), falling back to a clean temporary clone...",
+          cached_directory.display(),
+          error
+        );
+        // A failed `git fetch`/`checkout` isn't necessarily corruption (it
+        // could just be offline, or the ref doesn't exist) and `cached_directory`
+        // may be a user-supplied `--work-dir`, so never delete it here. Only
+        // a directory this tool manages itself under the default cache dir
+        // is safe to clean up, on a best-effort basis
+        if work_dir.is_none() {
+          let _ = fs::remove_dir_all(&cached_directory);
+        }
+        return fallback_temp_clone(repository, ferron_version);
+      }
+    }
+  }
 
   println!(
-    "Built Ferron for \"{}\" target successfully!",
-    target_triple
+    "Cloning the Git repository into \"{}\"...",
+    cached_directory.display()
   );
+  fs::create_dir_all(&cached_directory)?;
+  clone_repository(repository, &cached_directory, ferron_version)?;
+
+  Ok((cached_directory, None))
+}
+
+// Clones into a fresh temporary directory, used both for `--no-cache` and as
+// the safe fallback when reusing a cached checkout fails
+fn fallback_temp_clone(
+  repository: &str,
+  ferron_version: &str,
+) -> Result<(PathBuf, Option<tempfile::TempDir>), Box<dyn Error + Send + Sync>> {
+  println!("Creating temporary directory...");
+  let temporary_directory = tempfile::tempdir()?;
+  println!("Cloning the Git repository...");
+  clone_repository(repository, temporary_directory.path(), ferron_version)?;
+  let path = temporary_directory.path().to_path_buf();
+
+  Ok((path, Some(temporary_directory)))
+}
+
+// Derives a stable, per-repository cache directory under the user's cache dir
+fn cache_dir_for_repository(repository: &str) -> PathBuf {
+  let mut hasher = Sha256::new();
+  hasher.update(repository.as_bytes());
+  let digest = format!("{:x}", hasher.finalize());
+
+  dirs::cache_dir()
+    .unwrap_or_else(std::env::temp_dir)
+    .join("ferron-forge")
+    .join(digest)
+}
+
+// Clones the Git repository into `destination` and checks out the requested ref
+fn clone_repository(
+  repository: &str,
+  destination: &Path,
+  ferron_version: &str,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+  let prepare_clone = gix::prepare_clone(repository, destination)?;
+  let (mut prepare_checkout, _) = prepare_clone
+    .with_ref_name(ferron_version.into())?
+    .fetch_then_checkout(gix::progress::Discard, &IS_INTERRUPTED)?;
+  prepare_checkout.main_worktree(gix::progress::Discard, &IS_INTERRUPTED)?;
+
+  Ok(())
+}
+
+// Updates an existing cached checkout in place via `git fetch` + `git
+// checkout`, instead of a full re-clone
+fn fetch_and_checkout(
+  destination: &Path,
+  ferron_version: &str,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+  let fetch_status = std::process::Command::new("git")
+    .args(["fetch", "--quiet", "origin", ferron_version])
+    .current_dir(destination)
+    .status()?;
+  if !fetch_status.success() {
+    Err(anyhow::anyhow!(
+      "`git fetch` failed in the cached checkout"
+    ))?
+  }
+
+  let checkout_status = std::process::Command::new("git")
+    .args(["checkout", "--quiet", "--force", "FETCH_HEAD"])
+    .current_dir(destination)
+    .status()?;
+  if !checkout_status.success() {
+    Err(anyhow::anyhow!(
+      "`git checkout` failed in the cached checkout"
+    ))?
+  }
 
   Ok(())
 }
 
+// Loads the requested named profile from a `forge.toml` config file. The
+// config path is either given explicitly via `--config`, or discovered as
+// `forge.toml` in the current directory; it's an error to request a
+// profile when no config file can be found, or when the profile is missing
+fn load_profile(
+  config_path: Option<&str>,
+  profile_name: Option<&str>,
+) -> Result<Option<ForgeProfile>, Box<dyn Error + Send + Sync>> {
+  let resolved_config_path = match config_path {
+    Some(path) => Some(PathBuf::from(path)),
+    None => {
+      let default_path = PathBuf::from(DEFAULT_CONFIG_FILE_NAME);
+      default_path.is_file().then_some(default_path)
+    }
+  };
+
+  let Some(resolved_config_path) = resolved_config_path else {
+    if let Some(profile_name) = profile_name {
+      Err(anyhow::anyhow!(
+        "Profile \"{}\" was requested, but no `forge.toml` config file was found",
+        profile_name
+      ))?
+    }
+    return Ok(None);
+  };
+
+  let config_contents = fs::read_to_string(&resolved_config_path)?;
+  let config: ForgeConfig = toml::from_str(&config_contents)?;
+
+  match profile_name {
+    Some(profile_name) => match config.profile.get(profile_name) {
+      Some(profile) => Ok(Some(profile.clone())),
+      None => Err(anyhow::anyhow!(
+        "Profile \"{}\" was not found in \"{}\"",
+        profile_name,
+        resolved_config_path.display()
+      ))?,
+    },
+    None => Ok(None),
+  }
+}
+
 // Helper to retrieve the default Rust toolchain from rustup settings
 fn get_rustup_toolchain(rustup_directory: PathBuf) -> Result<String, Box<dyn Error + Send + Sync>> {
   let mut rustup_settings_path = rustup_directory;
@@ -161,11 +742,102 @@ fn get_rustup_toolchain(rustup_directory: PathBuf) -> Result<String, Box<dyn Err
   }
 }
 
+// Installs a target's standard library via `rustup target add` if it isn't
+// already present for the given toolchain (or the default toolchain, when
+// `toolchain` is `None`)
+fn ensure_target_installed(
+  toolchain: Option<&str>,
+  triplet: &str,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+  let mut list_command = std::process::Command::new("rustup");
+  list_command.args(["target", "list", "--installed"]);
+  if let Some(toolchain) = toolchain {
+    list_command.args(["--toolchain", toolchain]);
+  }
+  let installed_targets = list_command.output()?;
+  let already_installed = installed_targets.status.success()
+    && String::from_utf8_lossy(&installed_targets.stdout)
+      .lines()
+      .any(|installed| installed == triplet);
+
+  if already_installed {
+    return Ok(());
+  }
+
+  println!("Installing missing target \"{}\"...", triplet);
+  let mut add_command = std::process::Command::new("rustup");
+  add_command.args(["target", "add"]);
+  if let Some(toolchain) = toolchain {
+    add_command.args(["--toolchain", toolchain]);
+  }
+  add_command.arg(triplet);
+
+  let status = add_command.status()?;
+  if !status.success() {
+    Err(anyhow::anyhow!(
+      "Failed to install target \"{}\" via `rustup target add`",
+      triplet
+    ))?
+  }
+
+  Ok(())
+}
+
+// Installs a rustup component (e.g. "rust-src") for the given toolchain via
+// `rustup component add`
+fn ensure_component_installed(
+  toolchain: Option<&str>,
+  component: &str,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+  println!("Installing rustup component \"{}\"...", component);
+  let mut add_command = std::process::Command::new("rustup");
+  add_command.args(["component", "add"]);
+  if let Some(toolchain) = toolchain {
+    add_command.args(["--toolchain", toolchain]);
+  }
+  add_command.arg(component);
+
+  let status = add_command.status()?;
+  if !status.success() {
+    Err(anyhow::anyhow!(
+      "Failed to install component \"{}\" via `rustup component add`",
+      component
+    ))?
+  }
+
+  Ok(())
+}
+
+// Reads a toolchain channel/version pin from the cloned repo's
+// `rust-toolchain.toml` (a `[toolchain]` table with a `channel` key) or
+// legacy plain-text `rust-toolchain` file, if either is present
+fn read_repo_toolchain(repo_root: &Path) -> Option<String> {
+  if let Ok(toml_contents) = fs::read_to_string(repo_root.join("rust-toolchain.toml")) {
+    if let Ok(table) = toml_contents.parse::<Table>() {
+      if let Some(channel) = table
+        .get("toolchain")
+        .and_then(|toolchain| toolchain.get("channel"))
+        .and_then(|channel| channel.as_str())
+      {
+        return Some(channel.to_string());
+      }
+    }
+  }
+
+  fs::read_to_string(repo_root.join("rust-toolchain"))
+    .ok()
+    .map(|contents| contents.trim().to_string())
+}
+
 // Compiles the Ferron project using Cargo APIs
 fn compile(
   mut workspace_directory: PathBuf,
   target: Option<&str>,
   modules: Option<&[String]>,
+  auto_install: bool,
+  components: Option<&[String]>,
+  toolchain_override: Option<&str>,
+  respect_repo_toolchain: bool,
 ) -> Result<(Vec<UnitOutput>, String), Box<dyn Error + Send + Sync>> {
   let default_modules = modules.is_none();
 
@@ -182,21 +854,45 @@ fn compile(
     None => CompileKind::Host,
   };
 
+  let repo_root = workspace_directory.clone();
   // Append `Cargo.toml` to path for creating workspace
   workspace_directory.push("Cargo.toml");
 
+  // Resolve the toolchain to build with, in order of precedence: an explicit
+  // `--toolchain` override, the cloned repo's `rust-toolchain(.toml)` (when
+  // `--respect-repo-toolchain` is passed), then the rustup default
+  let mut resolved_toolchain = toolchain_override.map(str::to_string);
+  if resolved_toolchain.is_none() && respect_repo_toolchain {
+    resolved_toolchain = read_repo_toolchain(&repo_root);
+  }
+
   // Set rustup environment variables for toolchain resolution
   if let Ok(rustup_home) = home::rustup_home() {
+    if resolved_toolchain.is_none() {
+      resolved_toolchain = get_rustup_toolchain(rustup_home.clone()).ok();
+    }
     // Safety: The std::env::set_var function is safe to call in a single-threaded program. It's called before creating global context for Cargo.
-    if let Ok(toolchain) = get_rustup_toolchain(rustup_home.clone()) {
+    if let Some(toolchain) = &resolved_toolchain {
       #[allow(irrefutable_let_patterns)]
-      if let Ok(toolchain) = OsString::from_str(&toolchain) {
-        std::env::set_var("RUSTUP_TOOLCHAIN", toolchain);
+      if let Ok(toolchain_os_string) = OsString::from_str(toolchain) {
+        std::env::set_var("RUSTUP_TOOLCHAIN", toolchain_os_string);
       }
     }
     std::env::set_var("RUSTUP_HOME", rustup_home.into_os_string());
   }
 
+  // Make sure the requested target (and any requested components) are
+  // installed for the resolved toolchain before handing off to Cargo,
+  // rather than letting the build fail with a cryptic missing-std error
+  if auto_install {
+    if let Some(triplet) = target {
+      ensure_target_installed(resolved_toolchain.as_deref(), triplet)?;
+    }
+    for component in components.unwrap_or(&[]) {
+      ensure_component_installed(resolved_toolchain.as_deref(), component)?;
+    }
+  }
+
   // Initialize Cargo's global context and workspace
   let global_context = GlobalContext::default()?;
   global_context.shell().set_verbosity(Verbosity::Normal);
@@ -220,6 +916,11 @@ fn compile(
   // Execute the compilation
   let compilation = cargo::ops::compile(&workspace, &compile_options)?;
 
-  // Return the binaries and host/target triple
-  Ok((compilation.binaries, compilation.host))
+  // Return the binaries along with the triple that was actually requested
+  // (falling back to the host triple for a non-cross-compiled build), rather
+  // than `compilation.host`, which is always the build host
+  Ok((
+    compilation.binaries,
+    target.map(str::to_string).unwrap_or(compilation.host),
+  ))
 }